@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+use futures::{join, pin_mut, select, FutureExt};
+
+use crate::timer_future::TimerFuture;
+
+/// 模拟一个耗时的异步任务，在不同时刻打印一条带标记的消息。
+async fn learn() {
+    println!("[learn]  start");
+    TimerFuture::new(Duration::from_millis(100)).await;
+    println!("[learn]  done");
+}
+
+async fn sing() {
+    println!("[sing]   start");
+    TimerFuture::new(Duration::from_millis(200)).await;
+    println!("[sing]   done");
+}
+
+async fn dance() {
+    println!("[dance]  start");
+    TimerFuture::new(Duration::from_millis(50)).await;
+    println!("[dance]  done");
+}
+
+/// 用 `join!` 在同一个任务上并发驱动三个状态机，而不是依次 await 它们。
+/// 三条 "start" 打印会紧挨着出现，证明 `join!` 是交替轮询而不是顺序执行。
+pub async fn learn_and_sing_and_dance() {
+    join!(learn(), sing(), dance());
+}
+
+/// 用 `select!` 展示"谁先完成就先返回谁"，`dance`（50ms）理应总是第一个打印 "done"。
+pub async fn race_learn_sing_dance() {
+    let learn_fut = learn().fuse();
+    let sing_fut = sing().fuse();
+    let dance_fut = dance().fuse();
+    pin_mut!(learn_fut, sing_fut, dance_fut);
+
+    select! {
+        () = learn_fut => println!("[select] learn finished first"),
+        () = sing_fut => println!("[select] sing finished first"),
+        () = dance_fut => println!("[select] dance finished first"),
+    }
+}
@@ -0,0 +1,77 @@
+use std::future::Future;
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::task::Context;
+
+use futures::future::BoxFuture;
+use futures::task::{waker_ref, ArcWake};
+
+/// 任务队列的最大容量，超过后 `spawn` 会阻塞。
+const MAX_QUEUED_TASKS: usize = 10_000;
+
+/// 一个被执行器轮询的任务：持有 future 本身和重新入队所需的发送端。
+pub struct Task {
+    future: Mutex<Option<BoxFuture<'static, ()>>>,
+    task_sender: SyncSender<Arc<Task>>,
+}
+
+impl ArcWake for Task {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        let cloned = arc_self.clone();
+        arc_self
+            .task_sender
+            .send(cloned)
+            .expect("executor has been dropped");
+    }
+}
+
+/// 向执行器提交新任务的句柄。
+#[derive(Clone)]
+pub struct Spawner {
+    task_sender: SyncSender<Arc<Task>>,
+}
+
+impl Spawner {
+    pub fn spawn(&self, future: impl Future<Output = ()> + Send + 'static) {
+        let future = Box::pin(future);
+        let task = Arc::new(Task {
+            future: Mutex::new(Some(future)),
+            task_sender: self.task_sender.clone(),
+        });
+        self.task_sender.send(task).expect("executor has been dropped");
+    }
+}
+
+/// 从就绪队列里取出任务并驱动到完成（或下一次 `Poll::Pending`）的执行器。
+pub struct Executor {
+    ready_queue: Receiver<Arc<Task>>,
+}
+
+impl Executor {
+    pub fn run(&self) {
+        while let Ok(task) = self.ready_queue.recv() {
+            let mut future_slot = task.future.lock().unwrap();
+            if let Some(mut future) = future_slot.take() {
+                let waker = waker_ref(&task);
+                let context = &mut Context::from_waker(&waker);
+                if future.as_mut().poll(context).is_pending() {
+                    *future_slot = Some(future);
+                }
+            }
+        }
+    }
+}
+
+/// 构造一对 `(Executor, Spawner)`；发送端的队列容量由 `MAX_QUEUED_TASKS` 限定。
+pub fn new_executor_and_spawner() -> (Executor, Spawner) {
+    let (task_sender, ready_queue) = sync_channel(MAX_QUEUED_TASKS);
+    (Executor { ready_queue }, Spawner { task_sender })
+}
+
+/// 在新建的执行器上运行一个 future 直至完成，作为 `block_on` 的手写替代。
+pub fn run_to_completion(future: impl Future<Output = ()> + Send + 'static) {
+    let (executor, spawner) = new_executor_and_spawner();
+    spawner.spawn(future);
+    drop(spawner);
+    executor.run();
+}
@@ -0,0 +1,63 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::future::Ready;
+
+/// `foo` 被编译器展开后大致会落入这几种状态，这里手写出来做对照。
+enum FooState {
+    Start,
+    WaitingReady(Ready<i32>),
+    Done,
+}
+
+/// `foo` 的手写状态机版本：`async fn` 在编译期生成的匿名 future，
+/// 结构上就是一个 `state` 字段加上跨越 `.await` 存活的局部变量（这里是 `y`）。
+pub struct FooFuture {
+    state: FooState,
+    y: i32,
+}
+
+impl FooFuture {
+    pub fn new() -> Self {
+        FooFuture {
+            state: FooState::Start,
+            y: 0,
+        }
+    }
+}
+
+impl Future for FooFuture {
+    type Output = i32;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<i32> {
+        // FooState 不含自引用指针，直接取可变引用是安全的。
+        let this = self.get_mut();
+        loop {
+            match &mut this.state {
+                FooState::Start => {
+                    println!("[state] Start");
+                    let x = 1;
+                    this.y = x + 2;
+                    this.state = FooState::WaitingReady(futures::future::ready(42));
+                }
+                FooState::WaitingReady(ready) => {
+                    let inner = Pin::new(ready);
+                    match inner.poll(cx) {
+                        Poll::Pending => {
+                            println!("[state] WaitingReady -> Pending");
+                            return Poll::Pending;
+                        }
+                        Poll::Ready(_) => {
+                            println!("[state] WaitingReady -> Ready");
+                            let result = this.y + 10;
+                            this.state = FooState::Done;
+                            return Poll::Ready(result);
+                        }
+                    }
+                }
+                FooState::Done => panic!("FooFuture polled after completion"),
+            }
+        }
+    }
+}
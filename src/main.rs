@@ -1,19 +1,67 @@
-use futures::executor::block_on;
+mod concurrency;
+mod executor;
+mod foo_state_machine;
+mod streams;
+mod timer_future;
+
+use std::time::Duration;
+
+use foo_state_machine::FooFuture;
+use timer_future::TimerFuture;
 
 async fn foo() -> i32 {
     let x = 1;
     let y = x + 2;
-    
+
     // 引入一个 .await 来观察状态机的生成
     futures::future::ready(42).await;
-    
-    let result = y + 10;
-    result
+
+    y + 10
 }
 
-fn main() {
-    let result = block_on(foo());
-    println!("Result: {}", result);
+/// `foo` 的变体：await 一个真正会挂起的 `TimerFuture`，而不是立即就绪的
+/// `futures::future::ready`，这样才能观察到 `Poll::Pending` 和 waker 的触发。
+async fn foo_with_timer() -> i32 {
+    let x = 1;
+    let y = x + 2;
+
+    TimerFuture::new(Duration::from_millis(200)).await;
+
+    y + 10
 }
 
+fn main() {
+    // 用手写的 Spawner/Executor 替代 `block_on`，这样每一次 poll / wake
+    // 都经过我们自己的代码，而不是被库函数隐藏起来。
+    executor::run_to_completion(async {
+        let result = foo().await;
+        println!("Result: {}", result);
+    });
+
+    // 手写的状态机版本，行为与 `foo` 完全一致，但每次状态切换都会打印出来，
+    // 这样就能看到编译器替我们生成了什么。
+    executor::run_to_completion(async {
+        let result = FooFuture::new().await;
+        println!("Result (hand-rolled state machine): {}", result);
+    });
+
+    // 这次 await 的是 TimerFuture，任务会先收到 Poll::Pending，
+    // 在后台线程睡眠结束、调用 waker.wake() 之后才被重新调度。
+    executor::run_to_completion(async {
+        let result = foo_with_timer().await;
+        println!("Result (timer future): {}", result);
+    });
 
+    // `join!` 把 learn/sing/dance 三个状态机放在同一个任务里交替轮询，
+    // 它们的 "start" 打印会挤在一起，而不是像同步代码那样一个接一个。
+    executor::run_to_completion(concurrency::learn_and_sing_and_dance());
+
+    // `select!` 则是谁先就绪就先返回谁，其余分支被丢弃。
+    executor::run_to_completion(concurrency::race_learn_sing_dance());
+
+    // `Counter` 是一个会被反复 poll_next 的 Stream，而不是只产生一个值的 Future。
+    executor::run_to_completion(streams::consume_counter(5));
+
+    // 有界 mpsc channel：发送端在 channel 满时必须等待，体现出 backpressure。
+    executor::run_to_completion(streams::channel_producer_consumer());
+}
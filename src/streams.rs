@@ -0,0 +1,64 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::channel::mpsc;
+use futures::stream::Stream;
+use futures::{SinkExt, StreamExt};
+
+/// 一个从 0 数到 `limit - 1` 的 `Stream`，每次 `poll_next` 吐出一个值，
+/// 用完之后返回 `None` —— 和只产生一个值的 `Future` 不同，它会被反复轮询。
+pub struct Counter {
+    count: i32,
+    limit: i32,
+}
+
+impl Counter {
+    pub fn new(limit: i32) -> Self {
+        Counter { count: 0, limit }
+    }
+}
+
+impl Stream for Counter {
+    type Item = i32;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<i32>> {
+        let this = self.get_mut();
+        if this.count < this.limit {
+            let current = this.count;
+            this.count += 1;
+            Poll::Ready(Some(current))
+        } else {
+            Poll::Ready(None)
+        }
+    }
+}
+
+/// 用 `while let Some(x) = stream.next().await` 消费 `Counter`，
+/// 对照 `foo` 的一次性 `Future`，体会"反复轮询直到 `None`"这种形状。
+pub async fn consume_counter(limit: i32) {
+    let mut stream = Counter::new(limit);
+    while let Some(x) = stream.next().await {
+        println!("[counter] {}", x);
+    }
+}
+
+/// 用有界的 `mpsc` channel 演示生产者/消费者：发送端在 channel 满时
+/// 必须 await，这就是 backpressure —— 总是立即就绪的 `Counter` 和 `foo` 的一次性状态机都体现不出来。
+pub async fn channel_producer_consumer() {
+    let (mut tx, mut rx) = mpsc::channel::<i32>(2);
+
+    let producer = async move {
+        for i in 0..5 {
+            println!("[producer] sending {}", i);
+            tx.send(i).await.expect("receiver dropped");
+        }
+    };
+
+    let consumer = async move {
+        while let Some(x) = rx.next().await {
+            println!("[consumer] received {}", x);
+        }
+    };
+
+    futures::join!(producer, consumer);
+}